@@ -0,0 +1,130 @@
+
+use wasm_bindgen::prelude::*;
+use std::collections::BTreeMap as Base;
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+type BTreeMap=*mut OrderedMap;
+
+
+// A key wrapper whose ordering defers to the JS comparator supplied at
+// construction time. The comparator must return a negative/zero/positive
+// number and stay a stable total order for the lifetime of the map;
+// breaking that invariant corrupts the tree's internal ordering.
+struct OrdKey {
+  value: JsValue,
+  cmp: Rc<js_sys::Function>,
+}
+
+impl OrdKey {
+  fn new(value: JsValue,cmp: Rc<js_sys::Function>)-> Self {
+    Self { value,cmp }
+  }
+}
+
+impl Ord for OrdKey {
+  fn cmp(&self,other: &Self)-> Ordering {
+    let sign=self.cmp
+      .call2(&JsValue::NULL,&self.value,&other.value)
+      .unwrap_throw()
+      .as_f64()
+      .unwrap_throw();
+    sign.partial_cmp(&0.0).unwrap_or(Ordering::Equal)
+  }
+}
+
+impl PartialOrd for OrdKey {
+  fn partial_cmp(&self,other: &Self)-> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl PartialEq for OrdKey {
+  fn eq(&self,other: &Self)-> bool {
+    self.cmp(other)==Ordering::Equal
+  }
+}
+
+impl Eq for OrdKey {}
+
+
+struct OrderedMap {
+  cmp: Rc<js_sys::Function>,
+  inner: Base<OrdKey,JsValue>,
+}
+
+impl OrderedMap {
+  fn key(&self,value: JsValue)-> OrdKey {
+    OrdKey::new(value,self.cmp.clone())
+  }
+}
+
+
+#[wasm_bindgen(js_name = btree_map_new)]
+pub fn new(comparator: &js_sys::Function)-> BTreeMap {
+  as_ptr!(OrderedMap {
+    cmp: Rc::new(comparator.clone()),
+    inner: Base::new(),
+  })
+}
+
+#[wasm_bindgen(js_name = btree_map_insert)]
+pub fn insert(this: BTreeMap,key: JsValue,value: JsValue)-> JsValue {
+  unsafe {
+    let key=(*this).key(key);
+    (*this).inner.insert(key,value).unwrap_or(JsValue::UNDEFINED)
+  }
+}
+
+#[wasm_bindgen(js_name = btree_map_get)]
+pub fn get(this: BTreeMap,key: JsValue)-> JsValue {
+  unsafe {
+    let key=(*this).key(key);
+    (*this).inner.get(&key).cloned().unwrap_or(JsValue::UNDEFINED)
+  }
+}
+
+#[wasm_bindgen(js_name = btree_map_remove)]
+pub fn remove(this: BTreeMap,key: JsValue)-> JsValue {
+  unsafe {
+    let key=(*this).key(key);
+    (*this).inner.remove(&key).unwrap_or(JsValue::UNDEFINED)
+  }
+}
+
+// Returns the `[key, value]` pairs whose keys fall in the inclusive range `[lo, hi]`.
+#[wasm_bindgen(js_name = btree_map_range)]
+pub fn range(this: BTreeMap,lo: JsValue,hi: JsValue)-> js_sys::Array {
+  unsafe {
+    let lo=(*this).key(lo);
+    let hi=(*this).key(hi);
+    let pairs=js_sys::Array::new();
+    if lo.cmp(&hi)==Ordering::Greater {
+      return pairs;
+    }
+    for (key,value) in (*this).inner.range(lo..=hi) {
+      pairs.push(&js_sys::Array::of2(&key.value,value));
+    }
+    pairs
+  }
+}
+
+#[wasm_bindgen(js_name = btree_map_first)]
+pub fn first(this: BTreeMap)-> js_sys::Array {
+  unsafe {
+    match (*this).inner.first_key_value() {
+      Some((key,value))=> js_sys::Array::of2(&key.value,value),
+      None=> js_sys::Array::new(),
+    }
+  }
+}
+
+#[wasm_bindgen(js_name = btree_map_last)]
+pub fn last(this: BTreeMap)-> js_sys::Array {
+  unsafe {
+    match (*this).inner.last_key_value() {
+      Some((key,value))=> js_sys::Array::of2(&key.value,value),
+      None=> js_sys::Array::new(),
+    }
+  }
+}
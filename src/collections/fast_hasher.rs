@@ -0,0 +1,85 @@
+
+use std::collections::hash_map::{DefaultHasher,RandomState};
+use std::hash::{BuildHasher,Hasher};
+
+// 64-bit FxHash-style multiplier.
+const K: u64=0x51_7c_c1_b7_27_22_0a_95;
+
+
+// A fast, non-cryptographic hasher for the short string/integer keys
+// typical of JS interop. It trades SipHash's DoS resistance for throughput,
+// so only opt in when the key set is trusted.
+#[derive(Default)]
+pub struct FastHasher {
+  hash: u64,
+}
+
+impl FastHasher {
+  fn add_word(&mut self,word: u64) {
+    self.hash=(self.hash.rotate_left(5)^word).wrapping_mul(K);
+  }
+}
+
+impl Hasher for FastHasher {
+  fn finish(&self)-> u64 {
+    self.hash
+  }
+
+  fn write(&mut self,bytes: &[u8]) {
+    for chunk in bytes.chunks(8) {
+      let mut word=[0u8;8];
+      word[..chunk.len()].copy_from_slice(chunk);
+      self.add_word(u64::from_le_bytes(word));
+    }
+  }
+}
+
+
+// Runtime choice of hashing strategy so a single map/set representation — and
+// therefore one set of operations — serves both `new()` (SipHash, the
+// collision-resistant default) and `new_fast()` (FastHasher) callers. Keying
+// the constructors on distinct pointer types would leave the fast variant
+// write-only and alias incompatible allocations from JS.
+#[derive(Clone)]
+pub enum MapHasher {
+  Sip(RandomState),
+  Fast,
+}
+
+impl MapHasher {
+  pub fn sip()-> Self {
+    Self::Sip(RandomState::new())
+  }
+}
+
+impl BuildHasher for MapHasher {
+  type Hasher=MapHasherState;
+
+  fn build_hasher(&self)-> MapHasherState {
+    match self {
+      MapHasher::Sip(state)=> MapHasherState::Sip(state.build_hasher()),
+      MapHasher::Fast=> MapHasherState::Fast(FastHasher::default()),
+    }
+  }
+}
+
+pub enum MapHasherState {
+  Sip(DefaultHasher),
+  Fast(FastHasher),
+}
+
+impl Hasher for MapHasherState {
+  fn finish(&self)-> u64 {
+    match self {
+      MapHasherState::Sip(hasher)=> hasher.finish(),
+      MapHasherState::Fast(hasher)=> hasher.finish(),
+    }
+  }
+
+  fn write(&mut self,bytes: &[u8]) {
+    match self {
+      MapHasherState::Sip(hasher)=> hasher.write(bytes),
+      MapHasherState::Fast(hasher)=> hasher.write(bytes),
+    }
+  }
+}
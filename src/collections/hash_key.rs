@@ -0,0 +1,73 @@
+
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use std::hash::{Hash,Hasher};
+
+// A key wrapper giving `JsValue` value-based `Hash`/`Eq` semantics: a raw
+// `JsValue` only compares by heap-handle identity, so two separately-created
+// equal JS values would never match. Both are derived from a tagged string
+// `repr`, keeping hashing and equality consistent with each other.
+//
+// Primitives (null, undefined, booleans, numbers — including `NaN`/`±Infinity`
+// via their bit pattern — strings and BigInt) map to distinct keys. Functions,
+// symbols and other non-representable values have no structural form and are
+// keyed by type tag alone, so two distinct such values compare equal; they are
+// not meaningful map/set keys here.
+#[derive(Clone)]
+pub struct HashKey {
+  value: JsValue,
+}
+
+impl HashKey {
+  pub fn new(value: JsValue)-> Self {
+    Self { value }
+  }
+
+  fn repr(&self)-> String {
+    let value=&self.value;
+    if value.is_null() {
+      return "null".to_string();
+    }
+    if value.is_undefined() {
+      return "undefined".to_string();
+    }
+    if let Some(boolean)=value.as_bool() {
+      return format!("bool:{boolean}");
+    }
+    // Numbers, incl. `NaN`/`±Infinity` which JSON renders as `"null"`: key on
+    // the exact IEEE-754 bits so they never alias one another or `null`.
+    if let Some(number)=value.as_f64() {
+      return format!("num:{}",number.to_bits());
+    }
+    if let Some(string)=value.as_string() {
+      return format!("str:{string}");
+    }
+    // BigInt throws under `JSON.stringify`; key on its decimal form.
+    if let Ok(big)=value.clone().dyn_into::<js_sys::BigInt>() {
+      if let Ok(string)=big.to_string(10) {
+        return format!("big:{}",String::from(&string));
+      }
+    }
+    // Objects/arrays: structural JSON. `JSON.stringify` yields `undefined`
+    // rather than throwing for functions and symbols, so check for a string.
+    if let Some(string)=js_sys::JSON::stringify(value).ok().and_then(|string| string.as_string()) {
+      return format!("json:{string}");
+    }
+    // Functions, symbols and anything else with no representation.
+    format!("type:{}",value.js_typeof().as_string().unwrap_or_default())
+  }
+}
+
+impl Hash for HashKey {
+  fn hash<H: Hasher>(&self,state: &mut H) {
+    self.repr().hash(state);
+  }
+}
+
+impl PartialEq for HashKey {
+  fn eq(&self,other: &Self)-> bool {
+    self.repr()==other.repr()
+  }
+}
+
+impl Eq for HashKey {}
@@ -1,12 +1,45 @@
 
 use wasm_bindgen::prelude::*;
 use std::collections::HashMap as Base;
-type HashMap=*mut Base<JsValue,JsValue>;
+use super::fast_hasher::MapHasher;
+use super::hash_key::HashKey;
+type HashMap=*mut Base<HashKey,JsValue,MapHasher>;
 
 
-#[wasm_bindgen]
+#[wasm_bindgen(js_name = hash_map_new)]
 pub fn new()-> HashMap {
-  as_ptr!(Base::new())
+  as_ptr!(Base::with_hasher(MapHasher::sip()))
+}
+
+#[wasm_bindgen(js_name = hash_map_new_fast)]
+pub fn new_fast()-> HashMap {
+  as_ptr!(Base::with_hasher(MapHasher::Fast))
+}
+
+
+// Returns the existing value for `key`, or inserts and returns `default`.
+// Key equivalence follows [`HashKey`] (value-based, not handle identity).
+#[wasm_bindgen(js_name = hash_map_entry_or_insert)]
+pub fn entry_or_insert(this: HashMap,key: JsValue,default: JsValue)-> JsValue {
+  use std::collections::hash_map::Entry;
+  unsafe {
+    match (*this).entry(HashKey::new(key)) {
+      Entry::Occupied(entry)=> entry.get().clone(),
+      Entry::Vacant(entry)=> entry.insert(default).clone(),
+    }
+  }
+}
+
+// Like [`entry_or_insert`], but only invokes `factory` when the slot is vacant.
+#[wasm_bindgen(js_name = hash_map_entry_or_insert_with)]
+pub fn entry_or_insert_with(this: HashMap,key: JsValue,factory: &js_sys::Function)-> JsValue {
+  use std::collections::hash_map::Entry;
+  unsafe {
+    match (*this).entry(HashKey::new(key)) {
+      Entry::Occupied(entry)=> entry.get().clone(),
+      Entry::Vacant(entry)=> entry.insert(factory.call0(&JsValue::NULL).unwrap_throw()).clone(),
+    }
+  }
 }
 
 
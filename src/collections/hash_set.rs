@@ -0,0 +1,103 @@
+
+use wasm_bindgen::prelude::*;
+use std::collections::HashMap as Base;
+use super::fast_hasher::MapHasher;
+use super::hash_key::HashKey;
+type HashSet=*mut Base<HashKey,(),MapHasher>;
+
+
+#[wasm_bindgen(js_name = hash_set_new)]
+pub fn new()-> HashSet {
+  as_ptr!(Base::with_hasher(MapHasher::sip()))
+}
+
+#[wasm_bindgen(js_name = hash_set_new_fast)]
+pub fn new_fast()-> HashSet {
+  as_ptr!(Base::with_hasher(MapHasher::Fast))
+}
+
+#[wasm_bindgen(js_name = hash_set_insert)]
+pub fn insert(this: HashSet,element: JsValue)-> bool {
+  unsafe { (*this).insert(HashKey::new(element),()).is_none() }
+}
+
+#[wasm_bindgen(js_name = hash_set_contains)]
+pub fn contains(this: HashSet,element: JsValue)-> bool {
+  unsafe { (*this).contains_key(&HashKey::new(element)) }
+}
+
+#[wasm_bindgen(js_name = hash_set_remove)]
+pub fn remove(this: HashSet,element: JsValue)-> bool {
+  unsafe { (*this).remove(&HashKey::new(element)).is_some() }
+}
+
+#[wasm_bindgen(js_name = hash_set_len)]
+pub fn len(this: HashSet)-> usize {
+  unsafe { (*this).len() }
+}
+
+#[wasm_bindgen(js_name = hash_set_is_empty)]
+pub fn is_empty(this: HashSet)-> bool {
+  unsafe { (*this).is_empty() }
+}
+
+#[wasm_bindgen(js_name = hash_set_clear)]
+pub fn clear(this: HashSet) {
+  unsafe { (*this).clear() }
+}
+
+
+#[wasm_bindgen(js_name = hash_set_union)]
+pub fn union(this: HashSet,other: HashSet)-> HashSet {
+  unsafe {
+    let mut set=(*this).clone();
+    for key in (*other).keys() {
+      set.insert(key.clone(),());
+    }
+    as_ptr!(set)
+  }
+}
+
+#[wasm_bindgen(js_name = hash_set_intersection)]
+pub fn intersection(this: HashSet,other: HashSet)-> HashSet {
+  unsafe {
+    let mut set=Base::with_hasher((*this).hasher().clone());
+    for key in (*this).keys() {
+      if (*other).contains_key(key) {
+        set.insert(key.clone(),());
+      }
+    }
+    as_ptr!(set)
+  }
+}
+
+#[wasm_bindgen(js_name = hash_set_difference)]
+pub fn difference(this: HashSet,other: HashSet)-> HashSet {
+  unsafe {
+    let mut set=Base::with_hasher((*this).hasher().clone());
+    for key in (*this).keys() {
+      if !(*other).contains_key(key) {
+        set.insert(key.clone(),());
+      }
+    }
+    as_ptr!(set)
+  }
+}
+
+#[wasm_bindgen(js_name = hash_set_symmetric_difference)]
+pub fn symmetric_difference(this: HashSet,other: HashSet)-> HashSet {
+  unsafe {
+    let mut set=Base::with_hasher((*this).hasher().clone());
+    for key in (*this).keys() {
+      if !(*other).contains_key(key) {
+        set.insert(key.clone(),());
+      }
+    }
+    for key in (*other).keys() {
+      if !(*this).contains_key(key) {
+        set.insert(key.clone(),());
+      }
+    }
+    as_ptr!(set)
+  }
+}
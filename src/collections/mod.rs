@@ -2,9 +2,19 @@
 pub mod vec;
 pub mod slice;
 pub mod hash_map;
+pub mod hash_set;
+pub mod btree_map;
+pub mod fast_hasher;
+pub mod hash_key;
+pub mod zip;
 
 
 pub use vec::*;
 pub use slice::*;
 pub use hash_map::*;
+pub use hash_set::*;
+pub use btree_map::*;
+pub use fast_hasher::*;
+pub use hash_key::*;
+pub use zip::*;
 
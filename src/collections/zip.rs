@@ -0,0 +1,31 @@
+
+use wasm_bindgen::prelude::*;
+
+
+// Walks two numeric buffers in lockstep over `min(len_a, len_b)` positions,
+// invoking `f(a[i], b[i], i)`. The loop itself runs in Rust; only the call to
+// `f` crosses into JS, once per position. The
+// buffers arrive as `&[f64]`, which wasm-bindgen fills by copying from the JS
+// typed array (`Float64Array`, …) rather than reinterpreting a raw pointer
+// whose backing layout can't be confirmed here. This covers the paired
+// numeric workloads (distance/KNN, vector arithmetic) this bridge targets.
+// Any trailing elements of the longer buffer are ignored.
+#[wasm_bindgen]
+pub fn zip(a: &[f64],b: &[f64],f: &js_sys::Function) {
+  let len=a.len().min(b.len());
+  for i in 0..len {
+    f.call3(&JsValue::NULL,&JsValue::from_f64(a[i]),&JsValue::from_f64(b[i]),&JsValue::from_f64(i as f64)).unwrap_throw();
+  }
+}
+
+// Like [`zip`], but collects the callback's numeric results into a new `Vec`.
+#[wasm_bindgen]
+pub fn zip_map(a: &[f64],b: &[f64],f: &js_sys::Function)-> Vec<f64> {
+  let len=a.len().min(b.len());
+  let mut out=Vec::with_capacity(len);
+  for i in 0..len {
+    let result=f.call3(&JsValue::NULL,&JsValue::from_f64(a[i]),&JsValue::from_f64(b[i]),&JsValue::from_f64(i as f64)).unwrap_throw();
+    out.push(result.as_f64().unwrap_throw());
+  }
+  out
+}